@@ -27,6 +27,38 @@ pub struct Atten2p5dB<ADC: Adc>(PhantomData<ADC>);
 pub struct Atten6dB<ADC: Adc>(PhantomData<ADC>);
 pub struct Atten11dB<ADC: Adc>(PhantomData<ADC>);
 
+// Markers are phantom-typed over `ADC`, which isn't itself `defmt::Format`
+// (it's `ADC1`/`ADC2`), so `#[derive(defmt::Format)]` would add a spurious
+// `ADC: Format` bound nothing can satisfy. Implement manually instead, since
+// there's nothing channel/ADC-specific to log beyond the attenuation itself.
+#[cfg(feature = "defmt")]
+impl<ADC: Adc> defmt::Format for Atten0dB<ADC> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Atten0dB")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<ADC: Adc> defmt::Format for Atten2p5dB<ADC> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Atten2p5dB")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<ADC: Adc> defmt::Format for Atten6dB<ADC> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Atten6dB")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<ADC: Adc> defmt::Format for Atten11dB<ADC> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Atten11dB")
+    }
+}
+
 impl<ADC: Adc> Attenuation<ADC> for Atten0dB<ADC> {
     fn attenuation() -> adc_atten_t {
         adc_atten_t_ADC_ATTEN_DB_0
@@ -58,6 +90,7 @@ pub mod config {
 
     /// The sampling/readout resolution of the ADC
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub enum Resolution {
         #[cfg(esp32)]
         Resolution9Bit,
@@ -101,6 +134,7 @@ pub mod config {
     }
 
     #[derive(Debug, Copy, Clone, Default)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub struct Config {
         pub resolution: Resolution,
         #[cfg(esp_idf_comp_esp_adc_cal_enabled)]
@@ -168,6 +202,7 @@ pub struct AdcDriver<'d, ADC: Adc> {
     #[cfg(esp_idf_comp_esp_adc_cal_enabled)]
     cal_characteristics:
         Option<[Option<esp_adc_cal_characteristics_t>; adc_atten_t_ADC_ATTEN_DB_11 as usize + 1]>,
+    user_cal: [Option<(CalibrationPoint, CalibrationPoint)>; adc_atten_t_ADC_ATTEN_DB_11 as usize + 1],
 }
 
 #[cfg(not(feature = "riscv-ulp-hal"))]
@@ -215,14 +250,34 @@ impl<'d, ADC: Adc> AdcDriver<'d, ADC> {
             } else {
                 None
             },
+            user_cal: Default::default(),
         })
     }
 
+    /// Override calibration for `attenuation` with two measured reference
+    /// points, instead of relying on eFuse calibration (or the crude linear
+    /// fallback when eFuse calibration is unavailable)
+    ///
+    /// `raw_to_voltage` will linearly interpolate/extrapolate from `low` and
+    /// `high`, cached per attenuation the same way eFuse characteristics are.
+    pub fn set_calibration(
+        &mut self,
+        attenuation: adc_atten_t,
+        low: CalibrationPoint,
+        high: CalibrationPoint,
+    ) {
+        self.user_cal[attenuation as usize] = Some((low, high));
+    }
+
     fn raw_to_voltage(
         &mut self,
         measurement: c_types::c_int,
         attenuation: adc_atten_t,
     ) -> Result<u16, EspError> {
+        if let Some((low, high)) = self.user_cal[attenuation as usize] {
+            return Ok(Self::interpolate(measurement as u32, low, high));
+        }
+
         #[cfg(esp_idf_comp_esp_adc_cal_enabled)]
         let mv = if let Some(cal) = self.get_cal_characteristics(attenuation)? {
             unsafe { esp_adc_cal_raw_to_voltage(measurement as u32, &cal as *const _) as u16 }
@@ -236,6 +291,19 @@ impl<'d, ADC: Adc> AdcDriver<'d, ADC> {
         Ok(mv)
     }
 
+    fn interpolate(raw: u32, low: CalibrationPoint, high: CalibrationPoint) -> u16 {
+        let raw_span = high.raw as i64 - low.raw as i64;
+
+        if raw_span == 0 {
+            return low.millivolts;
+        }
+
+        let mv_span = high.millivolts as i64 - low.millivolts as i64;
+        let mv = low.millivolts as i64 + (raw as i64 - low.raw as i64) * mv_span / raw_span;
+
+        mv.clamp(0, u16::MAX as i64) as u16
+    }
+
     #[allow(non_upper_case_globals)]
     fn get_max_mv(attenuation: adc_atten_t) -> u32 {
         #[cfg(esp32)]
@@ -330,6 +398,54 @@ impl<'d, ADC: Adc> AdcDriver<'d, ADC> {
 
         Ok(self.raw_to_voltage(measurement, adc_atten_t_ADC_ATTEN_DB_0)?)
     }
+
+    /// Read a bank of channels in a single call, instead of one
+    /// [`embedded_hal_0_2::adc::OneShot::read`] per channel
+    ///
+    /// Each `channels` entry is reconfigured with its given attenuation and
+    /// then sampled, with the calibrated millivolt result written to the
+    /// matching slot in `out`. Returns the number of channels that were
+    /// sampled, i.e. `channels.len().min(out.len())`.
+    pub fn read_sequence(
+        &mut self,
+        channels: &[(adc_channel_t, adc_atten_t)],
+        out: &mut [ChannelValue],
+    ) -> Result<usize, EspError> {
+        let mut count = 0;
+
+        for (out_slot, &(channel, atten)) in out.iter_mut().zip(channels.iter()) {
+            if ADC::unit() == adc_unit_t_ADC_UNIT_1 {
+                esp!(unsafe { adc1_config_channel_atten(channel, atten) })?;
+            } else {
+                esp!(unsafe { adc2_config_channel_atten(channel, atten) })?;
+            }
+
+            let millivolts = nb::block!(self.read(ADC::unit(), channel, atten))?;
+
+            *out_slot = ChannelValue { channel, millivolts };
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+/// The calibrated result of sampling a single channel via
+/// [`AdcDriver::read_sequence`]
+#[cfg(not(feature = "riscv-ulp-hal"))]
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelValue {
+    pub channel: adc_channel_t,
+    pub millivolts: u16,
+}
+
+/// A single measured `(raw, millivolts)` reference point, as supplied to
+/// [`AdcDriver::set_calibration`]
+#[cfg(not(feature = "riscv-ulp-hal"))]
+#[derive(Debug, Clone, Copy)]
+pub struct CalibrationPoint {
+    pub raw: u32,
+    pub millivolts: u16,
 }
 
 #[cfg(not(feature = "riscv-ulp-hal"))]
@@ -359,6 +475,12 @@ impl<'d> embedded_hal_0_2::adc::OneShot<ADC1, u16, hall::HallSensor> for AdcDriv
     }
 }
 
+// NOTE: the released embedded-hal 1.0 dropped the `adc` module entirely (no
+// replacement ADC trait shipped in 1.0.0), so there is no eh1 trait to
+// implement here yet. Revisit if/when an ADC abstraction lands in
+// embedded-hal or a sibling crate (e.g. `embedded-hal-nb`), and name it
+// explicitly instead of assuming `embedded_hal_1::adc` exists.
+
 macro_rules! impl_adc {
     ($adc:ident: $unit:expr) => {
         crate::impl_peripheral!($adc);
@@ -374,3 +496,432 @@ macro_rules! impl_adc {
 
 impl_adc!(ADC1: adc_unit_t_ADC_UNIT_1);
 impl_adc!(ADC2: adc_unit_t_ADC_UNIT_2);
+
+/// Continuous (DMA-backed) ADC sampling
+///
+/// This uses the `adc_continuous_*` driver (I2S-DMA on ESP32) to stream
+/// conversions into a ring buffer, instead of blocking on one conversion
+/// at a time like [`AdcDriver`] does. The `adc_continuous_*` API was
+/// introduced in ESP-IDF 5.0, so this isn't available on IDF 4.x.
+#[cfg(all(esp32, not(feature = "riscv-ulp-hal"), not(esp_idf_version_major = "4")))]
+pub mod continuous {
+    use core::ptr;
+
+    use esp_idf_sys::*;
+
+    use crate::peripheral::{Peripheral, PeripheralRef};
+    use crate::task;
+
+    use super::{config::Resolution, Adc};
+
+    /// Notification bit set on the waiting task once a DMA conversion frame
+    /// is ready, via [`task::notify`]
+    const CONVERSION_DONE_NOTIFICATION: u32 = 1;
+
+    /// A single entry of the ADC digital controller's pattern table
+    ///
+    /// Mirrors `adc_digi_pattern_config_t`: each entry selects one channel,
+    /// attenuation and bit width to be sampled on every pass through the
+    /// pattern table.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Pattern {
+        pub channel: adc_channel_t,
+        pub attenuation: adc_atten_t,
+        pub bit_width: Resolution,
+    }
+
+    /// Configuration for [`AdcContinuousDriver`]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Config {
+        /// Sampling frequency of the digital controller, in Hz
+        pub sample_freq_hz: u32,
+        /// Size, in bytes, of the internal DMA ring buffer
+        pub ring_buffer_size: usize,
+        /// Size, in bytes, of a single conversion frame handed to the caller
+        pub frame_size: usize,
+    }
+
+    impl Config {
+        pub fn new() -> Self {
+            Default::default()
+        }
+
+        #[must_use]
+        pub fn sample_freq_hz(mut self, sample_freq_hz: u32) -> Self {
+            self.sample_freq_hz = sample_freq_hz;
+            self
+        }
+
+        #[must_use]
+        pub fn ring_buffer_size(mut self, ring_buffer_size: usize) -> Self {
+            self.ring_buffer_size = ring_buffer_size;
+            self
+        }
+
+        #[must_use]
+        pub fn frame_size(mut self, frame_size: usize) -> Self {
+            self.frame_size = frame_size;
+            self
+        }
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self {
+                sample_freq_hz: 20_000,
+                ring_buffer_size: 1024,
+                frame_size: 256,
+            }
+        }
+    }
+
+    /// A single decoded conversion result: the channel it came from and its
+    /// raw reading
+    #[derive(Debug, Clone, Copy)]
+    pub struct Sample {
+        pub channel: adc_channel_t,
+        pub raw: u16,
+    }
+
+    /// Iterator decoding `adc_digi_output_data_t` frames out of a buffer
+    /// filled by [`AdcContinuousDriver::read`]
+    pub struct Samples<'b> {
+        buf: &'b [u8],
+    }
+
+    impl<'b> Iterator for Samples<'b> {
+        type Item = Sample;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            const FRAME_SIZE: usize = core::mem::size_of::<adc_digi_output_data_t>();
+
+            if self.buf.len() < FRAME_SIZE {
+                return None;
+            }
+
+            let (frame, rest) = self.buf.split_at(FRAME_SIZE);
+            self.buf = rest;
+
+            let data: adc_digi_output_data_t =
+                unsafe { ptr::read_unaligned(frame.as_ptr() as *const _) };
+
+            let (channel, raw) = unsafe { (data.type1.channel(), data.type1.data()) };
+
+            Some(Sample {
+                channel: channel as adc_channel_t,
+                raw: raw as u16,
+            })
+        }
+    }
+
+    /// Driver for continuous, DMA-backed ADC sampling
+    ///
+    /// Samples the channels in `pattern` back-to-back at `config.sample_freq_hz`,
+    /// handing conversions off via DMA rather than one blocking register read
+    /// per sample. Use this instead of [`super::AdcDriver`] when audio/kHz-rate
+    /// sampling is needed.
+    pub struct AdcContinuousDriver<'d, ADC: Adc> {
+        _adc: PeripheralRef<'d, ADC>,
+        handle: adc_continuous_handle_t,
+    }
+
+    unsafe impl<'d, ADC: Adc> Send for AdcContinuousDriver<'d, ADC> {}
+
+    impl<'d, ADC: Adc> AdcContinuousDriver<'d, ADC> {
+        pub fn new(
+            adc: impl Peripheral<P = ADC> + 'd,
+            config: &Config,
+            pattern: &[Pattern],
+        ) -> Result<Self, EspError> {
+            crate::into_ref!(adc);
+
+            // ESP32's I2S-DMA continuous/digital-controller path only ever
+            // samples ADC1; `adc_digi_convert_mode_t_ADC_CONV_SINGLE_UNIT_1`
+            // below assumes this.
+            if ADC::unit() != adc_unit_t_ADC_UNIT_1 {
+                return Err(EspError::from(ESP_ERR_INVALID_ARG as esp_err_t).unwrap());
+            }
+
+            let mut handle: adc_continuous_handle_t = ptr::null_mut();
+
+            let handle_config = adc_continuous_handle_cfg_t {
+                max_store_buf_size: config.ring_buffer_size as u32,
+                conv_frame_size: config.frame_size as u32,
+            };
+
+            esp!(unsafe { adc_continuous_new_handle(&handle_config, &mut handle as *mut _) })?;
+
+            let adc_pattern: alloc::vec::Vec<adc_digi_pattern_config_t> = pattern
+                .iter()
+                .map(|p| adc_digi_pattern_config_t {
+                    atten: p.attenuation as u8,
+                    channel: p.channel as u8,
+                    unit: ADC::unit() as u8,
+                    bit_width: adc_bits_width_t::from(p.bit_width) as u8,
+                })
+                .collect();
+
+            let digi_config = adc_continuous_config_t {
+                pattern_num: adc_pattern.len() as u32,
+                adc_pattern: adc_pattern.as_ptr() as *mut _,
+                sample_freq_hz: config.sample_freq_hz,
+                conv_mode: adc_digi_convert_mode_t_ADC_CONV_SINGLE_UNIT_1,
+                format: adc_digi_output_format_t_ADC_DIGI_OUTPUT_FORMAT_TYPE1,
+            };
+
+            if let Err(err) = esp!(unsafe { adc_continuous_config(handle, &digi_config) }) {
+                unsafe {
+                    adc_continuous_deinit(handle);
+                }
+
+                return Err(err);
+            }
+
+            Ok(Self { _adc: adc, handle })
+        }
+
+        pub fn start(&mut self) -> Result<(), EspError> {
+            esp!(unsafe { adc_continuous_start(self.handle) })
+        }
+
+        pub fn stop(&mut self) -> Result<(), EspError> {
+            esp!(unsafe { adc_continuous_stop(self.handle) })
+        }
+
+        /// Fill `buf` with raw conversion frames, blocking for up to `timeout`
+        ///
+        /// Returns the number of bytes written into `buf`. Decode the result
+        /// with [`Samples`]/[`Self::samples`].
+        pub fn read(
+            &mut self,
+            buf: &mut [u8],
+            timeout: crate::delay::TickType,
+        ) -> Result<usize, EspError> {
+            let mut out_len = 0_u32;
+
+            esp!(unsafe {
+                adc_continuous_read(
+                    self.handle,
+                    buf.as_mut_ptr(),
+                    buf.len() as u32,
+                    &mut out_len as *mut _,
+                    timeout.0,
+                )
+            })?;
+
+            Ok(out_len as usize)
+        }
+
+        /// Register the "conversion done" callback that notifies the calling
+        /// task, enabling [`Self::read_notified`]
+        ///
+        /// Must be called from the task that will subsequently call
+        /// [`Self::read_notified`], as it captures that task's handle.
+        pub fn subscribe_notify(&mut self) -> Result<(), EspError> {
+            let task =
+                task::current().expect("subscribe_notify() must be called from a task context");
+
+            let cbs = adc_continuous_evt_cbs_t {
+                on_conv_done: Some(Self::on_conversion_done),
+                on_pool_ovf: None,
+            };
+
+            esp!(unsafe {
+                adc_continuous_register_event_callbacks(
+                    self.handle,
+                    &cbs as *const _,
+                    task as *mut c_types::c_void,
+                )
+            })
+        }
+
+        /// Park the calling task until a DMA conversion frame is ready, then
+        /// read it into `buf`
+        ///
+        /// Requires [`Self::subscribe_notify`] to have been called first from
+        /// the same task. This avoids busy-polling [`Self::read`] with a
+        /// short timeout.
+        pub fn read_notified(&mut self, buf: &mut [u8]) -> Result<usize, EspError> {
+            task::wait_any_notification();
+
+            self.read(buf, crate::delay::TickType::from(Some(core::time::Duration::ZERO)))
+        }
+
+        /// ISR callback registered via [`Self::subscribe_notify`]; runs in
+        /// IRAM and only notifies the waiting task `FromISR`
+        ///
+        /// The driver-level return value ESP-IDF expects here is "should a
+        /// yield happen", but [`task::notify`] already issues that yield
+        /// itself (via [`task::do_yield`]) when it wakes a higher-priority
+        /// task. Always return `false` so the yield isn't requested twice.
+        #[link_section = ".iram1.adc_continuous_on_conversion_done"]
+        extern "C" fn on_conversion_done(
+            _handle: adc_continuous_handle_t,
+            _edata: *const adc_continuous_evt_data_t,
+            user_data: *mut c_types::c_void,
+        ) -> bool {
+            let task = user_data as TaskHandle_t;
+
+            unsafe {
+                task::notify(task, CONVERSION_DONE_NOTIFICATION);
+            }
+
+            false
+        }
+
+        /// Decode the conversion frames filled in by [`Self::read`]
+        pub fn samples<'b>(&self, buf: &'b [u8]) -> Samples<'b> {
+            Samples { buf }
+        }
+    }
+
+    impl<'d, ADC: Adc> Drop for AdcContinuousDriver<'d, ADC> {
+        fn drop(&mut self) {
+            let _ = unsafe { adc_continuous_stop(self.handle) };
+            unsafe {
+                adc_continuous_deinit(self.handle);
+            }
+        }
+    }
+}
+
+/// On-die temperature sensor
+///
+/// ESP32-S2/S3/C3 (and later) have a dedicated temperature-sensor peripheral
+/// that, unlike the ESP32 hall sensor (see `hall::HallSensor` and
+/// `impl OneShot<ADC1, u16, HallSensor>`), isn't read through the ADC
+/// register path. This wraps `temperature_sensor_*` so both can be sampled
+/// without dropping to raw `esp-idf-sys` calls.
+#[cfg(all(
+    any(esp32s2, esp32s3, esp32c3),
+    not(feature = "riscv-ulp-hal"),
+    not(esp_idf_version_major = "4")
+))]
+pub mod tsens {
+    use esp_idf_sys::*;
+
+    /// Configuration for [`TempSensorDriver`]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Config {
+        /// Lower bound, in °C, of the expected measurement range
+        pub range_min: i32,
+        /// Upper bound, in °C, of the expected measurement range
+        pub range_max: i32,
+    }
+
+    impl Config {
+        pub fn new() -> Self {
+            Default::default()
+        }
+
+        #[must_use]
+        pub fn range(mut self, range_min: i32, range_max: i32) -> Self {
+            self.range_min = range_min;
+            self.range_max = range_max;
+            self
+        }
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self {
+                range_min: -10,
+                range_max: 80,
+            }
+        }
+    }
+
+    /// Driver for the on-die temperature sensor
+    pub struct TempSensorDriver {
+        handle: temperature_sensor_handle_t,
+    }
+
+    unsafe impl Send for TempSensorDriver {}
+
+    impl TempSensorDriver {
+        pub fn new(config: &Config) -> Result<Self, EspError> {
+            let mut handle: temperature_sensor_handle_t = core::ptr::null_mut();
+
+            // Default-init rather than naming every field: `flags` was only
+            // added to `temperature_sensor_config_t` in ESP-IDF 5.3, and this
+            // module also targets 5.0-5.2, where it doesn't exist.
+            let mut tsens_config: temperature_sensor_config_t = Default::default();
+            tsens_config.range_min = config.range_min;
+            tsens_config.range_max = config.range_max;
+            tsens_config.clk_src =
+                temperature_sensor_clk_src_t_TEMPERATURE_SENSOR_CLK_SRC_DEFAULT;
+
+            esp!(unsafe { temperature_sensor_install(&tsens_config, &mut handle as *mut _) })?;
+
+            if let Err(err) = esp!(unsafe { temperature_sensor_enable(handle) }) {
+                unsafe {
+                    temperature_sensor_uninstall(handle);
+                }
+
+                return Err(err);
+            }
+
+            Ok(Self { handle })
+        }
+
+        /// Read the current die temperature, in °C
+        pub fn get_celsius(&mut self) -> Result<f32, EspError> {
+            let mut celsius = 0_f32;
+
+            esp!(unsafe {
+                temperature_sensor_get_celsius(self.handle, &mut celsius as *mut _)
+            })?;
+
+            Ok(celsius)
+        }
+    }
+
+    impl Drop for TempSensorDriver {
+        fn drop(&mut self) {
+            let _ = unsafe { temperature_sensor_disable(self.handle) };
+            unsafe {
+                temperature_sensor_uninstall(self.handle);
+            }
+        }
+    }
+}
+
+/// On-die temperature sensor, legacy `temp_sensor_*` driver (ESP-IDF 4.x)
+#[cfg(all(
+    any(esp32s2, esp32c3),
+    not(feature = "riscv-ulp-hal"),
+    esp_idf_version_major = "4"
+))]
+pub mod tsens {
+    use esp_idf_sys::*;
+
+    /// Driver for the on-die temperature sensor
+    pub struct TempSensorDriver(());
+
+    unsafe impl Send for TempSensorDriver {}
+
+    impl TempSensorDriver {
+        pub fn new() -> Result<Self, EspError> {
+            esp!(unsafe { temp_sensor_start() })?;
+
+            Ok(Self(()))
+        }
+
+        /// Read the current die temperature, in °C
+        pub fn get_celsius(&mut self) -> Result<f32, EspError> {
+            let mut celsius = 0_f32;
+
+            esp!(unsafe { temp_sensor_read_celsius(&mut celsius as *mut _) })?;
+
+            Ok(celsius)
+        }
+    }
+
+    impl Drop for TempSensorDriver {
+        fn drop(&mut self) {
+            unsafe {
+                temp_sensor_stop();
+            }
+        }
+    }
+}